@@ -0,0 +1,142 @@
+use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Wilder-smooth a raw series: seed at `period - 1` with the sum of the
+/// first `period` values, then `smoothed[i] = smoothed[i-1] - smoothed[i-1]/period + values[i]`.
+/// Returns NaN for positions before the seed.
+fn wilder_smooth(values: &[f64], period: usize) -> Vec<f64> {
+    let n = values.len();
+    let mut smoothed = vec![f64::NAN; n];
+
+    if n < period || period == 0 {
+        return smoothed;
+    }
+
+    let mut sum = 0.0;
+    for v in &values[0..period] {
+        sum += v;
+    }
+    smoothed[period - 1] = sum;
+
+    for i in period..n {
+        smoothed[i] = smoothed[i - 1] - smoothed[i - 1] / period as f64 + values[i];
+    }
+
+    smoothed
+}
+
+/// Internal Wilder DMI/ADX computation.
+///
+/// Directional movement per bar: `up = high[i]-high[i-1]`, `down = low[i-1]-low[i]`;
+/// `+DM = up` when `up > down && up > 0`, else 0 (mirrored for `-DM`). True range
+/// follows the same rule as `indicators::atr`. TR, +DM and -DM are Wilder-smoothed,
+/// `+DI`/`-DI` are `100 * smoothed(dm) / smoothed(tr)`, `DX` is `100 * |+DI - -DI| / (+DI + -DI)`,
+/// and `ADX` is `DX` Wilder-smoothed a second time (seeded from the mean of the
+/// first `period` `DX` values). Returns NaN for all warm-up positions.
+fn adx_inner(high: &[f64], low: &[f64], close: &[f64], period: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let n = high.len();
+    let mut tr = vec![0.0; n];
+    let mut plus_dm = vec![0.0; n];
+    let mut minus_dm = vec![0.0; n];
+
+    if n == 0 || period == 0 {
+        return (vec![f64::NAN; n], vec![f64::NAN; n], vec![f64::NAN; n]);
+    }
+
+    tr[0] = high[0] - low[0];
+
+    for i in 1..n {
+        let up = high[i] - high[i - 1];
+        let down = low[i - 1] - low[i];
+
+        plus_dm[i] = if up > down && up > 0.0 { up } else { 0.0 };
+        minus_dm[i] = if down > up && down > 0.0 { down } else { 0.0 };
+
+        let hl = high[i] - low[i];
+        let hpc = (high[i] - close[i - 1]).abs();
+        let lpc = (low[i] - close[i - 1]).abs();
+
+        tr[i] = if hl >= hpc && hl >= lpc {
+            hl
+        } else if hpc >= hl && hpc >= lpc {
+            hpc
+        } else {
+            lpc
+        };
+    }
+
+    let smoothed_tr = wilder_smooth(&tr, period);
+    let smoothed_plus_dm = wilder_smooth(&plus_dm, period);
+    let smoothed_minus_dm = wilder_smooth(&minus_dm, period);
+
+    let mut plus_di = vec![f64::NAN; n];
+    let mut minus_di = vec![f64::NAN; n];
+    let mut dx = vec![f64::NAN; n];
+
+    for i in (period - 1)..n {
+        if smoothed_tr[i].is_nan() || smoothed_tr[i] == 0.0 {
+            continue;
+        }
+        plus_di[i] = 100.0 * smoothed_plus_dm[i] / smoothed_tr[i];
+        minus_di[i] = 100.0 * smoothed_minus_dm[i] / smoothed_tr[i];
+
+        let di_sum = plus_di[i] + minus_di[i];
+        // Both DIs are zero during a flat/non-trending stretch; define DX=0
+        // rather than leaving it NaN, since ADX's own recurrence would
+        // otherwise stay NaN for the rest of the series forever.
+        dx[i] = if di_sum != 0.0 {
+            100.0 * (plus_di[i] - minus_di[i]).abs() / di_sum
+        } else {
+            0.0
+        };
+    }
+
+    let mut adx = vec![f64::NAN; n];
+    let seed_index = 2 * period - 2;
+    if seed_index < n {
+        let mut sum_dx = 0.0;
+        for v in &dx[(period - 1)..=seed_index] {
+            sum_dx += v;
+        }
+        adx[seed_index] = sum_dx / period as f64;
+
+        for i in (seed_index + 1)..n {
+            adx[i] = (adx[i - 1] * (period as f64 - 1.0) + dx[i]) / period as f64;
+        }
+    }
+
+    (plus_di, minus_di, adx)
+}
+
+/// Wilder's Directional Movement Index and Average Directional Index.
+///
+/// Gauges trend strength independently of direction. `+DI`/`-DI` describe
+/// which side is dominant, and `ADX` (a double-smoothed `DX`) describes how
+/// strong the prevailing trend is — callers typically gate Ichimoku or Hull
+/// MA signals on `ADX > 20` to avoid acting on choppy, non-trending moves.
+/// Returns `(plus_di, minus_di, adx)`. Warm-up positions are NaN.
+#[pyfunction]
+#[pyo3(signature = (high, low, close, period=14))]
+pub fn adx<'py>(
+    py: Python<'py>,
+    high: PyReadonlyArray1<'py, f64>,
+    low: PyReadonlyArray1<'py, f64>,
+    close: PyReadonlyArray1<'py, f64>,
+    period: usize,
+) -> (
+    Bound<'py, PyArray1<f64>>,
+    Bound<'py, PyArray1<f64>>,
+    Bound<'py, PyArray1<f64>>,
+) {
+    let h = high.as_slice().unwrap();
+    let l = low.as_slice().unwrap();
+    let c = close.as_slice().unwrap();
+
+    let (plus_di, minus_di, adx) = adx_inner(h, l, c, period);
+
+    (
+        PyArray1::from_vec(py, plus_di),
+        PyArray1::from_vec(py, minus_di),
+        PyArray1::from_vec(py, adx),
+    )
+}
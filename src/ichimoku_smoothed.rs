@@ -0,0 +1,94 @@
+use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::ma::apply_ma_layered;
+
+/// Internal generalized Ichimoku line: selectable MA applied to `(high+low)/2`,
+/// optionally stacked across several smoothing passes.
+fn ichimoku_line_smoothed_inner(
+    high: &[f64],
+    low: &[f64],
+    period: usize,
+    ma_type: &str,
+    layers: usize,
+) -> PyResult<Vec<f64>> {
+    let n = high.len();
+
+    let mut hl_median = vec![0.0; n];
+    for i in 0..n {
+        hl_median[i] = (high[i] + low[i]) / 2.0;
+    }
+
+    apply_ma_layered(&hl_median, period, ma_type, layers)
+}
+
+/// Generalized Ichimoku line: a selectable moving average applied to the bar
+/// midpoint, optionally re-applied across multiple layers.
+///
+/// `ma_type` selects the smoother: `"wma"`, `"ema"`, `"smma"` (Wilder),
+/// `"hull"`, or `"kama"` (Kaufman Adaptive MA). `layers` (1-5) re-applies the
+/// chosen smoother that many times, trading lag for smoothness. This
+/// generalizes the Hull-only midpoint (`ichimoku_hull::ichimoku_line_hull`)
+/// into a single tunable entry point; the classic max/min midpoint
+/// (`ichimoku::ichimoku_line`) is not one of the selectable smoothers.
+/// Returns NaN for warm-up positions.
+#[pyfunction]
+pub fn ichimoku_line_smoothed<'py>(
+    py: Python<'py>,
+    high: PyReadonlyArray1<'py, f64>,
+    low: PyReadonlyArray1<'py, f64>,
+    period: usize,
+    ma_type: &str,
+    layers: usize,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let h = high.as_slice().unwrap();
+    let l = low.as_slice().unwrap();
+    let result = ichimoku_line_smoothed_inner(h, l, period, ma_type, layers)?;
+    Ok(PyArray1::from_vec(py, result))
+}
+
+/// Compute all four Ichimoku cloud components with a selectable, stackable smoother.
+///
+/// Returns `(tenkan, kijun, senkou_span_a, senkou_span_b)` where each line
+/// uses `ichimoku_line_smoothed` with the given `ma_type`/`layers`.
+/// `senkou_span_a` is NaN-aware: NaN if either `tenkan` or `kijun` is NaN.
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub fn ichimoku_components_smoothed<'py>(
+    py: Python<'py>,
+    high: PyReadonlyArray1<'py, f64>,
+    low: PyReadonlyArray1<'py, f64>,
+    tenkan_period: usize,
+    kijun_period: usize,
+    senkou_period: usize,
+    ma_type: &str,
+    layers: usize,
+) -> PyResult<(
+    Bound<'py, PyArray1<f64>>,
+    Bound<'py, PyArray1<f64>>,
+    Bound<'py, PyArray1<f64>>,
+    Bound<'py, PyArray1<f64>>,
+)> {
+    let h = high.as_slice().unwrap();
+    let l = low.as_slice().unwrap();
+    let n = h.len();
+
+    let tenkan = ichimoku_line_smoothed_inner(h, l, tenkan_period, ma_type, layers)?;
+    let kijun = ichimoku_line_smoothed_inner(h, l, kijun_period, ma_type, layers)?;
+
+    let mut senkou_a = vec![f64::NAN; n];
+    for i in 0..n {
+        if !tenkan[i].is_nan() && !kijun[i].is_nan() {
+            senkou_a[i] = (tenkan[i] + kijun[i]) / 2.0;
+        }
+    }
+
+    let senkou_b = ichimoku_line_smoothed_inner(h, l, senkou_period, ma_type, layers)?;
+
+    Ok((
+        PyArray1::from_vec(py, tenkan),
+        PyArray1::from_vec(py, kijun),
+        PyArray1::from_vec(py, senkou_a),
+        PyArray1::from_vec(py, senkou_b),
+    ))
+}
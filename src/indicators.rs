@@ -91,3 +91,116 @@ pub fn atr<'py>(
 
     PyArray1::from_vec(py, result)
 }
+
+/// Relative Strength Index with Wilder smoothing.
+///
+/// `delta = close[i] - close[i-1]`, `gain = max(delta, 0)`, `loss = max(-delta, 0)`.
+/// Average gain/loss is seeded as the simple mean of the first `period` deltas
+/// (at index `period`), then Wilder-smoothed:
+/// `avg_gain[i] = (avg_gain[i-1]*(period-1) + gain[i]) / period` (same for loss).
+/// `RS = avg_gain / avg_loss`, `RSI = 100 - 100/(1+RS)`, with `RSI = 100` when
+/// `avg_loss == 0`. NaN for warm-up positions.
+#[pyfunction]
+#[pyo3(signature = (close, period=14))]
+pub fn rsi<'py>(
+    py: Python<'py>,
+    close: PyReadonlyArray1<'py, f64>,
+    period: usize,
+) -> Bound<'py, PyArray1<f64>> {
+    let c = close.as_slice().unwrap();
+    let n = c.len();
+
+    let mut result = vec![f64::NAN; n];
+
+    if n <= period || period == 0 {
+        return PyArray1::from_vec(py, result);
+    }
+
+    let mut gain = vec![0.0; n];
+    let mut loss = vec![0.0; n];
+    for i in 1..n {
+        let delta = c[i] - c[i - 1];
+        gain[i] = delta.max(0.0);
+        loss[i] = (-delta).max(0.0);
+    }
+
+    let mut sum_gain = 0.0;
+    let mut sum_loss = 0.0;
+    for i in 1..=period {
+        sum_gain += gain[i];
+        sum_loss += loss[i];
+    }
+
+    let mut avg_gain = sum_gain / period as f64;
+    let mut avg_loss = sum_loss / period as f64;
+    result[period] = rsi_from_averages(avg_gain, avg_loss);
+
+    for i in (period + 1)..n {
+        avg_gain = (avg_gain * (period as f64 - 1.0) + gain[i]) / period as f64;
+        avg_loss = (avg_loss * (period as f64 - 1.0) + loss[i]) / period as f64;
+        result[i] = rsi_from_averages(avg_gain, avg_loss);
+    }
+
+    PyArray1::from_vec(py, result)
+}
+
+/// `RSI = 100 - 100/(1+RS)` where `RS = avg_gain/avg_loss`; `100` when `avg_loss == 0`.
+fn rsi_from_averages(avg_gain: f64, avg_loss: f64) -> f64 {
+    if avg_loss == 0.0 {
+        100.0
+    } else {
+        let rs = avg_gain / avg_loss;
+        100.0 - 100.0 / (1.0 + rs)
+    }
+}
+
+/// Bollinger Bands: an SMA middle band with population-std-based envelopes.
+///
+/// `middle` is the simple moving average over `period`. `upper`/`lower` are
+/// `middle ± num_std * rolling population std`. NaN for warm-up positions.
+/// Feeding the Hull-smoothed Ichimoku midline (`ichimoku_hull::ichimoku_line_hull`)
+/// as `data` hugs the low-lag band and flags volatility expansion/contraction.
+#[pyfunction]
+#[pyo3(signature = (data, period=20, num_std=2.0))]
+pub fn bollinger<'py>(
+    py: Python<'py>,
+    data: PyReadonlyArray1<'py, f64>,
+    period: usize,
+    num_std: f64,
+) -> (
+    Bound<'py, PyArray1<f64>>,
+    Bound<'py, PyArray1<f64>>,
+    Bound<'py, PyArray1<f64>>,
+) {
+    let d = data.as_slice().unwrap();
+    let n = d.len();
+
+    let mut middle = vec![f64::NAN; n];
+    let mut upper = vec![f64::NAN; n];
+    let mut lower = vec![f64::NAN; n];
+
+    if n < period || period == 0 {
+        return (
+            PyArray1::from_vec(py, middle),
+            PyArray1::from_vec(py, upper),
+            PyArray1::from_vec(py, lower),
+        );
+    }
+
+    for i in (period - 1)..n {
+        let window = &d[(i - period + 1)..=i];
+        let mean = window.iter().sum::<f64>() / period as f64;
+        let variance = window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / period as f64;
+        let std_dev = variance.sqrt();
+
+        middle[i] = mean;
+        upper[i] = mean + num_std * std_dev;
+        lower[i] = mean - num_std * std_dev;
+    }
+
+    (
+        PyArray1::from_vec(py, middle),
+        PyArray1::from_vec(py, upper),
+        PyArray1::from_vec(py, lower),
+    )
+}
@@ -1,10 +1,15 @@
 use pyo3::prelude::*;
 
+mod adx;
 mod hull;
 mod hull_signals;
 mod ichimoku;
 mod ichimoku_hull;
+mod ichimoku_smoothed;
 mod indicators;
+mod ma;
+mod risk;
+mod signals;
 
 /// Rust-accelerated Ichimoku Cloud with Hull MA smoothing.
 /// Enhanced technical analysis: classic + Hull-based Ichimoku components.
@@ -19,7 +24,16 @@ fn advanced_ichimoku_cloud(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(ichimoku::ichimoku_components, m)?)?;
     m.add_function(wrap_pyfunction!(ichimoku_hull::ichimoku_line_hull, m)?)?;
     m.add_function(wrap_pyfunction!(ichimoku_hull::ichimoku_components_hull, m)?)?;
+    m.add_function(wrap_pyfunction!(ichimoku_smoothed::ichimoku_line_smoothed, m)?)?;
+    m.add_function(wrap_pyfunction!(ichimoku_smoothed::ichimoku_components_smoothed, m)?)?;
     m.add_function(wrap_pyfunction!(indicators::ema, m)?)?;
     m.add_function(wrap_pyfunction!(indicators::atr, m)?)?;
+    m.add_function(wrap_pyfunction!(indicators::rsi, m)?)?;
+    m.add_function(wrap_pyfunction!(indicators::bollinger, m)?)?;
+    m.add_function(wrap_pyfunction!(adx::adx, m)?)?;
+    m.add_function(wrap_pyfunction!(signals::ichimoku_signal, m)?)?;
+    m.add_function(wrap_pyfunction!(risk::atr_stop, m)?)?;
+    m.add_function(wrap_pyfunction!(risk::position_size, m)?)?;
+    m.add_function(wrap_pyfunction!(risk::trailing_atr_stop, m)?)?;
     Ok(())
 }
@@ -0,0 +1,158 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::PyResult;
+
+pub(crate) use crate::hull::{hullma_inner, wma_inner};
+
+/// Index of the first non-NaN value, or `None` if the slice is all NaN.
+///
+/// `wma`/`hull` recompute from a raw trailing window at each index so they
+/// self-heal after a NaN prefix, but the recurrence-based smoothers below
+/// carry `result[i-1]` forward — a stacked layer feeds them the previous
+/// layer's NaN warm-up prefix as input, so they must restart their seed from
+/// the first valid value instead of assuming it lives at index 0.
+fn first_valid_index(values: &[f64]) -> Option<usize> {
+    values.iter().position(|v| !v.is_nan())
+}
+
+/// Internal EMA with NaN warm-up, seeded from the SMA of the first `period`
+/// values after the input's own NaN prefix.
+///
+/// Unlike `indicators::ema` (which seeds from `data[0]` and fills the whole
+/// array), this variant follows the WMA/Hull warm-up convention so it can be
+/// stacked and compared against the other selectable smoothers.
+pub(crate) fn ema_inner(prices: &[f64], period: usize) -> Vec<f64> {
+    let n = prices.len();
+    let mut ema = vec![f64::NAN; n];
+
+    if period == 0 {
+        return ema;
+    }
+
+    let Some(start) = first_valid_index(prices) else {
+        return ema;
+    };
+    if n - start < period {
+        return ema;
+    }
+
+    let seed_index = start + period - 1;
+    let sum: f64 = prices[start..start + period].iter().sum();
+    ema[seed_index] = sum / period as f64;
+
+    let alpha = 2.0 / (period as f64 + 1.0);
+    for i in (seed_index + 1)..n {
+        ema[i] = alpha * prices[i] + (1.0 - alpha) * ema[i - 1];
+    }
+
+    ema
+}
+
+/// Internal SMMA (Wilder moving average), seeded from the SMA of the first
+/// `period` values after the input's own NaN prefix.
+///
+/// `smma[i] = (smma[i-1] * (period - 1) + x[i]) / period`.
+pub(crate) fn smma_inner(prices: &[f64], period: usize) -> Vec<f64> {
+    let n = prices.len();
+    let mut smma = vec![f64::NAN; n];
+
+    if period == 0 {
+        return smma;
+    }
+
+    let Some(start) = first_valid_index(prices) else {
+        return smma;
+    };
+    if n - start < period {
+        return smma;
+    }
+
+    let seed_index = start + period - 1;
+    let sum: f64 = prices[start..start + period].iter().sum();
+    smma[seed_index] = sum / period as f64;
+
+    for i in (seed_index + 1)..n {
+        smma[i] = (smma[i - 1] * (period as f64 - 1.0) + prices[i]) / period as f64;
+    }
+
+    smma
+}
+
+/// Internal KAMA (Kaufman Adaptive Moving Average) with fast=2, slow=30.
+///
+/// Efficiency ratio `ER = |x[i]-x[i-period]| / sum(|x[j]-x[j-1]|)` over the
+/// trailing window, smoothing constant `SC = (ER*(2/3 - 2/31) + 2/31)^2`,
+/// and `kama[i] = kama[i-1] + SC*(x[i]-kama[i-1])`. Seeded with `x[period]`
+/// relative to the input's own NaN prefix.
+pub(crate) fn kama_inner(prices: &[f64], period: usize) -> Vec<f64> {
+    let n = prices.len();
+    let mut kama = vec![f64::NAN; n];
+
+    if period == 0 {
+        return kama;
+    }
+
+    let Some(start) = first_valid_index(prices) else {
+        return kama;
+    };
+    if n - start <= period {
+        return kama;
+    }
+
+    const FASTEST: f64 = 2.0 / 3.0;
+    const SLOWEST: f64 = 2.0 / 31.0;
+
+    let seed_index = start + period;
+    kama[seed_index] = prices[seed_index];
+
+    for i in (seed_index + 1)..n {
+        let change = (prices[i] - prices[i - period]).abs();
+
+        let mut volatility = 0.0;
+        for j in (i - period + 1)..=i {
+            volatility += (prices[j] - prices[j - 1]).abs();
+        }
+
+        let er = if volatility == 0.0 { 0.0 } else { change / volatility };
+        let sc = (er * (FASTEST - SLOWEST) + SLOWEST).powi(2);
+
+        kama[i] = kama[i - 1] + sc * (prices[i] - kama[i - 1]);
+    }
+
+    kama
+}
+
+/// Apply the moving average named by `ma_type` once.
+///
+/// Recognized values: `"wma"`, `"ema"`, `"smma"`, `"hull"`, `"kama"`.
+fn apply_ma(prices: &[f64], period: usize, ma_type: &str) -> PyResult<Vec<f64>> {
+    match ma_type {
+        "wma" => Ok(wma_inner(prices, period)),
+        "ema" => Ok(ema_inner(prices, period)),
+        "smma" => Ok(smma_inner(prices, period)),
+        "hull" => Ok(hullma_inner(prices, period)),
+        "kama" => Ok(kama_inner(prices, period)),
+        other => Err(PyValueError::new_err(format!(
+            "unknown ma_type '{other}', expected one of: wma, ema, smma, hull, kama"
+        ))),
+    }
+}
+
+/// Apply the moving average named by `ma_type` `layers` times (1-5), each pass
+/// smoothing the previous pass's output for a progressively smoother band.
+pub(crate) fn apply_ma_layered(
+    prices: &[f64],
+    period: usize,
+    ma_type: &str,
+    layers: usize,
+) -> PyResult<Vec<f64>> {
+    if !(1..=5).contains(&layers) {
+        return Err(PyValueError::new_err("layers must be between 1 and 5"));
+    }
+
+    let mut result = apply_ma(prices, period, ma_type)?;
+    for _ in 1..layers {
+        result = apply_ma(&result, period, ma_type)?;
+    }
+
+    Ok(result)
+}
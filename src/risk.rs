@@ -0,0 +1,83 @@
+use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// ATR-driven stop-loss level: wider stops as volatility (ATR) rises.
+///
+/// `entry_price - multiplier*atr` for longs (`direction >= 0`),
+/// `entry_price + multiplier*atr` for shorts (`direction < 0`).
+#[pyfunction]
+#[pyo3(signature = (entry_price, atr, direction, multiplier=3.0))]
+pub fn atr_stop(entry_price: f64, atr: f64, direction: i32, multiplier: f64) -> f64 {
+    if direction >= 0 {
+        entry_price - multiplier * atr
+    } else {
+        entry_price + multiplier * atr
+    }
+}
+
+/// Volatility-scaled position size: units such that `|entry-stop|*units == account_risk`.
+#[pyfunction]
+pub fn position_size(account_risk: f64, entry_price: f64, stop_price: f64) -> f64 {
+    let per_unit_risk = (entry_price - stop_price).abs();
+    if per_unit_risk == 0.0 {
+        0.0
+    } else {
+        account_risk / per_unit_risk
+    }
+}
+
+/// Internal chandelier-style trailing ATR stop computation.
+fn trailing_atr_stop_inner(close: &[f64], atr_series: &[f64], direction: i32, multiplier: f64) -> Vec<f64> {
+    let n = close.len();
+    let mut stops = vec![f64::NAN; n];
+
+    if n == 0 {
+        return stops;
+    }
+
+    if direction >= 0 {
+        let mut running_high = close[0];
+        for i in 0..n {
+            running_high = running_high.max(close[i]);
+            let candidate = running_high - multiplier * atr_series[i];
+            stops[i] = if i == 0 { candidate } else { candidate.max(stops[i - 1]) };
+        }
+    } else {
+        let mut running_low = close[0];
+        for i in 0..n {
+            running_low = running_low.min(close[i]);
+            let candidate = running_low + multiplier * atr_series[i];
+            stops[i] = if i == 0 { candidate } else { candidate.min(stops[i - 1]) };
+        }
+    }
+
+    stops
+}
+
+/// Chandelier-style trailing ATR stop, ratcheting only in the favorable direction.
+///
+/// For longs (`direction >= 0`): the highest close seen so far minus
+/// `multiplier*atr_series[i]`, never allowed to move down. For shorts: the
+/// lowest close seen so far plus `multiplier*atr_series[i]`, never allowed to
+/// move up. Pairs with `indicators::atr` for the `atr_series` input.
+#[pyfunction]
+pub fn trailing_atr_stop<'py>(
+    py: Python<'py>,
+    close: PyReadonlyArray1<'py, f64>,
+    atr_series: PyReadonlyArray1<'py, f64>,
+    direction: i32,
+    multiplier: f64,
+) -> PyResult<Bound<'py, PyArray1<f64>>> {
+    let c = close.as_slice().unwrap();
+    let a = atr_series.as_slice().unwrap();
+
+    if a.len() != c.len() {
+        return Err(PyValueError::new_err(
+            "atr_series must be the same length as close",
+        ));
+    }
+
+    let result = trailing_atr_stop_inner(c, a, direction, multiplier);
+    Ok(PyArray1::from_vec(py, result))
+}
@@ -0,0 +1,106 @@
+use numpy::PyReadonlyArray1;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Internal composite Ichimoku signal over the last bar.
+///
+/// Bullish requires: close above both senkou spans, tenkan crossing above
+/// kijun on the last bar, RSI above `rsi_midline`, and ADX above
+/// `adx_threshold`. Bearish mirrors each condition. Returns
+/// `(direction, price_above_cloud, tk_bullish_cross, rsi_confirms, adx_trending)`.
+#[allow(clippy::too_many_arguments)]
+fn ichimoku_signal_inner(
+    tenkan: &[f64],
+    kijun: &[f64],
+    senkou_a: &[f64],
+    senkou_b: &[f64],
+    close: &[f64],
+    rsi: &[f64],
+    adx: &[f64],
+    rsi_midline: f64,
+    adx_threshold: f64,
+) -> (i32, bool, bool, bool, bool) {
+    let n = close.len();
+    if n < 2 || tenkan.len() < 2 || kijun.len() < 2 {
+        return (0, false, false, false, false);
+    }
+
+    let last = n - 1;
+    let close_last = close[last];
+    let sa_last = senkou_a[last];
+    let sb_last = senkou_b[last];
+
+    let price_above_cloud = close_last > sa_last && close_last > sb_last;
+    let price_below_cloud = close_last < sa_last && close_last < sb_last;
+
+    let tk_bullish_cross = tenkan[last - 1] <= kijun[last - 1] && tenkan[last] > kijun[last];
+    let tk_bearish_cross = tenkan[last - 1] >= kijun[last - 1] && tenkan[last] < kijun[last];
+
+    let rsi_last = rsi[last];
+    let adx_last = adx[last];
+    let adx_trending = !adx_last.is_nan() && adx_last > adx_threshold;
+
+    let rsi_confirms = if price_above_cloud {
+        !rsi_last.is_nan() && rsi_last > rsi_midline
+    } else if price_below_cloud {
+        !rsi_last.is_nan() && rsi_last < rsi_midline
+    } else {
+        false
+    };
+
+    let bullish = price_above_cloud && tk_bullish_cross && rsi_confirms && adx_trending;
+    let bearish = price_below_cloud && tk_bearish_cross && rsi_confirms && adx_trending;
+
+    let direction = if bullish {
+        1
+    } else if bearish {
+        -1
+    } else {
+        0
+    };
+
+    (direction, price_above_cloud, tk_bullish_cross, rsi_confirms, adx_trending)
+}
+
+/// Composite Ichimoku signal: cloud position, TK cross, RSI and ADX gating.
+///
+/// Combines precomputed `tenkan`/`kijun`/`senkou_a`/`senkou_b` with `close`,
+/// an `rsi` array (see `indicators::rsi`) and an `adx` array (see `adx::adx`)
+/// to produce one cautious directional call rather than raw line outputs.
+/// Bullish requires the latest close above both senkou spans, tenkan crossing
+/// above kijun on the last bar, RSI above `rsi_midline`, and ADX above
+/// `adx_threshold`; bearish mirrors every condition.
+///
+/// Returns `(direction, price_above_cloud, tk_bullish_cross, rsi_confirms, adx_trending)`
+/// where `direction` is `1` (bullish), `-1` (bearish) or `0` (no signal).
+#[pyfunction]
+#[pyo3(signature = (tenkan, kijun, senkou_a, senkou_b, close, rsi, adx, rsi_midline=50.0, adx_threshold=20.0))]
+#[allow(clippy::too_many_arguments)]
+pub fn ichimoku_signal(
+    tenkan: PyReadonlyArray1<'_, f64>,
+    kijun: PyReadonlyArray1<'_, f64>,
+    senkou_a: PyReadonlyArray1<'_, f64>,
+    senkou_b: PyReadonlyArray1<'_, f64>,
+    close: PyReadonlyArray1<'_, f64>,
+    rsi: PyReadonlyArray1<'_, f64>,
+    adx: PyReadonlyArray1<'_, f64>,
+    rsi_midline: f64,
+    adx_threshold: f64,
+) -> PyResult<(i32, bool, bool, bool, bool)> {
+    let t = tenkan.as_slice().unwrap();
+    let k = kijun.as_slice().unwrap();
+    let sa = senkou_a.as_slice().unwrap();
+    let sb = senkou_b.as_slice().unwrap();
+    let c = close.as_slice().unwrap();
+    let r = rsi.as_slice().unwrap();
+    let a = adx.as_slice().unwrap();
+
+    let n = c.len();
+    if t.len() != n || k.len() != n || sa.len() != n || sb.len() != n || r.len() != n || a.len() != n {
+        return Err(PyValueError::new_err(
+            "tenkan, kijun, senkou_a, senkou_b, rsi and adx must all be the same length as close",
+        ));
+    }
+
+    Ok(ichimoku_signal_inner(t, k, sa, sb, c, r, a, rsi_midline, adx_threshold))
+}